@@ -1,9 +1,64 @@
-use cosmwasm_std::{Deps, DepsMut, Env, MessageInfo, Response, StdResult, StdError, Addr, Uint128};
-use crate::reputation::{Review, UserReputation, ReputationParams, REVIEWS, USER_REVIEWS, USERS, REPUTATION_PARAMS};
-use crate::reputation::{calculate_reputation_score, verify_transaction_proof, verify_signature};
+use cosmwasm_std::{
+    BankMsg, Coin, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, StdError, Addr,
+    Timestamp, Uint128,
+};
+use cw_storage_plus::Bound;
+use crate::reputation::{
+    Dispute, DisputeStatus, Review, UserReputation, ReputationParams, ReputationStatus, Stake,
+    ADMIN, ARBITERS, DISPUTES, REVIEWS, REVIEWER_KEYS, USER_REVIEWS, USERS, REPUTATION_PARAMS,
+    STAKES, STAKE_DENOM,
+};
+use crate::reputation::{
+    calculate_reputation_score, reputation_status, review_signing_message, verify_signature,
+    verify_transaction_proof,
+};
 
-pub fn submit_review(
+const DEFAULT_SWEEP_LIMIT: u32 = 10;
+const MAX_SWEEP_LIMIT: u32 = 30;
+/// Fraction of stale counters aged out per elapsed decay period.
+const SWEEP_DECAY_DENOMINATOR: u32 = 24;
+
+/// Seeds the admin and the initial arbiter allow-list at instantiation.
+/// Without this, ARBITERS defaults to empty and flag_dispute/resolve_dispute
+/// can never be called successfully.
+pub fn instantiate(
     deps: DepsMut,
+    _env: Env,
+    info: MessageInfo,
+    admin: Option<Addr>,
+    arbiters: Vec<Addr>,
+) -> StdResult<Response> {
+    let admin = admin.unwrap_or_else(|| info.sender.clone());
+    ADMIN.save(deps.storage, &admin)?;
+    ARBITERS.save(deps.storage, &arbiters)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute("admin", admin)
+        .add_attribute("arbiter_count", arbiters.len().to_string()))
+}
+
+/// Replaces the arbiter allow-list wholesale. Restricted to `ADMIN`, which
+/// lets the admin rotate arbiters without redeploying the contract.
+pub fn set_arbiters(deps: DepsMut, _env: Env, info: MessageInfo, arbiters: Vec<Addr>) -> StdResult<Response> {
+    require_admin(deps.as_ref(), &info.sender)?;
+    ARBITERS.save(deps.storage, &arbiters)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "set_arbiters")
+        .add_attribute("arbiter_count", arbiters.len().to_string()))
+}
+
+fn require_admin(deps: Deps, sender: &Addr) -> StdResult<()> {
+    let admin = ADMIN.load(deps.storage)?;
+    if sender != admin {
+        return Err(StdError::generic_err("Unauthorized: sender is not the admin"));
+    }
+    Ok(())
+}
+
+pub fn submit_review(
+    mut deps: DepsMut,
     env: Env,
     info: MessageInfo,
     service: String,
@@ -17,11 +72,55 @@ pub fn submit_review(
         return Err(StdError::generic_err("Rating must be between 1 and 5"));
     }
 
-    // Verify transaction proof
-    if !verify_transaction_proof(&transaction_proof) {
+    // Gate submission on the reviewer's reputation status
+    let params = REPUTATION_PARAMS.load(deps.storage)?;
+    if let Some(user_rep) = USERS.may_load(deps.storage, &info.sender)? {
+        match reputation_status(&user_rep, &params) {
+            ReputationStatus::Banned => {
+                return Err(StdError::generic_err("Reviewer is banned from submitting reviews"));
+            }
+            ReputationStatus::Throttled => {
+                let elapsed = env.block.time.seconds() - user_rep.last_activity.seconds();
+                if elapsed < params.throttle_window {
+                    return Err(StdError::generic_err(
+                        "Reviewer is throttled: only one review per window is allowed",
+                    ));
+                }
+            }
+            ReputationStatus::Ok => {}
+        }
+    }
+
+    // Require the reviewer to hold at least the minimum bonded stake
+    let bonded = STAKES
+        .may_load(deps.storage, &info.sender)?
+        .map(|s| s.amount)
+        .unwrap_or_default();
+    if bonded < params.min_stake {
+        return Err(StdError::generic_err(
+            "Reviewer does not hold the minimum required stake",
+        ));
+    }
+
+    // Verify the transaction proof binds the reviewer to the reviewed service,
+    // and the signature proves the reviewer's registered key authored these
+    // exact fields. Both checks fail closed: any error rejects the review
+    // outright. The public key is never caller-supplied: it must already be
+    // bound to this reviewer via register_reviewer_key, so a reviewer can't
+    // mint a fresh throwaway keypair per submission and self-sign.
+    if !verify_transaction_proof(&transaction_proof, &info.sender, &service)? {
         return Err(StdError::generic_err("Invalid transaction proof"));
     }
 
+    let public_key = REVIEWER_KEYS.may_load(deps.storage, &info.sender)?.ok_or_else(|| {
+        StdError::generic_err("Reviewer has not registered a public key")
+    })?;
+
+    let signing_message = review_signing_message(&info.sender, &service, rating, &transaction_proof);
+    if !verify_signature(deps.api, &signing_message, &signature, &public_key)? {
+        return Err(StdError::generic_err("Invalid review signature"));
+    }
+
     // Create review ID using timestamp and reviewer
     let review_id = format!("{}-{}", env.block.time.seconds(), info.sender);
 
@@ -42,8 +141,10 @@ pub fn submit_review(
     REVIEWS.save(deps.storage, review_id.clone(), &review)?;
     USER_REVIEWS.save(deps.storage, (&info.sender, review_id.clone()), &review_id)?;
 
-    // Update user reputation
-    update_user_reputation(deps, &info.sender, &env)?;
+    // Update user reputation, then record the new review as seen and
+    // (provisionally, until disputed) honored.
+    update_user_reputation(deps.branch(), &info.sender, &env)?;
+    bump_review_counters(deps, &info.sender)?;
 
     Ok(Response::new()
         .add_attribute("action", "submit_review")
@@ -52,24 +153,78 @@ pub fn submit_review(
         .add_attribute("review_id", review_id))
 }
 
-pub fn flag_dispute(
+/// Registers the ed25519 public key a reviewer will use to sign reviews.
+/// One-time binding: once set, it cannot be swapped out for a different key,
+/// which is what stops a reviewer from signing with a new throwaway keypair
+/// on every submission.
+pub fn register_reviewer_key(
     deps: DepsMut,
     _env: Env,
     info: MessageInfo,
+    public_key: Vec<u8>,
+) -> StdResult<Response> {
+    if REVIEWER_KEYS.has(deps.storage, &info.sender) {
+        return Err(StdError::generic_err(
+            "Reviewer already has a registered public key",
+        ));
+    }
+
+    REVIEWER_KEYS.save(deps.storage, &info.sender, &public_key)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "register_reviewer_key")
+        .add_attribute("reviewer", info.sender))
+}
+
+pub fn flag_dispute(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
     review_id: String,
     reason: String,
 ) -> StdResult<Response> {
-    let mut review = REVIEWS.load(deps.storage, review_id.clone())?;
+    require_arbiter(deps.as_ref(), &info.sender)?;
 
-    // Only service owner or admin can flag disputes
-    // TODO: Add proper authorization check
+    // A review already adjudicated (Upheld or Rejected) can't be reopened:
+    // re-flagging an Upheld dispute would reset its status to Open and let
+    // resolve_dispute slash the reviewer's stake a second time for the same
+    // incident.
+    if let Some(existing) = DISPUTES.may_load(deps.storage, review_id.clone())? {
+        if existing.status != DisputeStatus::Open {
+            return Err(StdError::generic_err(
+                "Dispute for this review has already been resolved",
+            ));
+        }
+    }
+
+    let mut review = REVIEWS.load(deps.storage, review_id.clone())?;
 
+    let already_disputed = review.is_disputed;
     review.is_disputed = true;
     review.dispute_reason = Some(reason.clone());
     REVIEWS.save(deps.storage, review_id.clone(), &review)?;
 
-    // Update reputation scores for the reviewer
-    update_user_reputation(deps, &review.reviewer, &_env)?;
+    // Update reputation scores for the reviewer, demoting this review out of
+    // the honored count now that it's disputed (provisionally, pending
+    // arbitration; resolve_dispute restores it if the flag is rejected).
+    update_user_reputation(deps.branch(), &review.reviewer, &env)?;
+    if !already_disputed {
+        demote_review_honor(deps.branch(), &review.reviewer)?;
+    }
+
+    // Open a dispute awaiting arbitration. No stake is slashed here: slashing
+    // only happens once an arbiter upholds the dispute in resolve_dispute, so
+    // a bogus flag can never burn funds before it's adjudicated.
+    DISPUTES.save(
+        deps.storage,
+        review_id.clone(),
+        &Dispute {
+            review_id: review_id.clone(),
+            flagger: info.sender.clone(),
+            reason: reason.clone(),
+            status: DisputeStatus::Open,
+        },
+    )?;
 
     Ok(Response::new()
         .add_attribute("action", "flag_dispute")
@@ -78,6 +233,225 @@ pub fn flag_dispute(
         .add_attribute("reason", reason))
 }
 
+/// Resolves an open dispute. Restricted to arbiters. `Upheld` slashes the
+/// reviewer's bonded stake on top of the reputation penalty already applied
+/// at flag time; `Rejected` reverses the dispute flag and records a false
+/// flag against whoever raised it.
+pub fn resolve_dispute(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    review_id: String,
+    outcome: DisputeStatus,
+) -> StdResult<Response> {
+    require_arbiter(deps.as_ref(), &info.sender)?;
+
+    let mut dispute = DISPUTES.load(deps.storage, review_id.clone())?;
+    if dispute.status != DisputeStatus::Open {
+        return Err(StdError::generic_err("Dispute has already been resolved"));
+    }
+
+    let mut response = Response::new()
+        .add_attribute("action", "resolve_dispute")
+        .add_attribute("review_id", review_id.clone())
+        .add_attribute("arbiter", info.sender.clone());
+
+    match outcome {
+        DisputeStatus::Upheld => {
+            // Only a dispute an arbiter actually upholds slashes stake, so a
+            // bogus flag can never burn funds before it's adjudicated.
+            let review = REVIEWS.load(deps.storage, review_id.clone())?;
+            let params = REPUTATION_PARAMS.load(deps.storage)?;
+            if let Some(slash_msg) = slash_stake(deps.branch(), &review.reviewer, &params)? {
+                response = response.add_message(slash_msg);
+            }
+            dispute.status = DisputeStatus::Upheld;
+        }
+        DisputeStatus::Rejected => {
+            let mut review = REVIEWS.load(deps.storage, review_id.clone())?;
+            review.is_disputed = false;
+            REVIEWS.save(deps.storage, review_id.clone(), &review)?;
+
+            update_user_reputation(deps.branch(), &review.reviewer, &env)?;
+            restore_review_honor(deps.branch(), &review.reviewer)?;
+            record_false_flag(deps.branch(), &dispute.flagger)?;
+
+            dispute.status = DisputeStatus::Rejected;
+        }
+        DisputeStatus::Open => {
+            return Err(StdError::generic_err("Cannot resolve a dispute back to Open"));
+        }
+    }
+
+    DISPUTES.save(deps.storage, review_id.clone(), &dispute)?;
+
+    Ok(response.add_attribute("outcome", format!("{:?}", dispute.status)))
+}
+
+fn require_arbiter(deps: Deps, sender: &Addr) -> StdResult<()> {
+    let arbiters = ARBITERS.may_load(deps.storage)?.unwrap_or_default();
+    if !arbiters.contains(sender) {
+        return Err(StdError::generic_err("Unauthorized: sender is not an arbiter"));
+    }
+    Ok(())
+}
+
+/// Records a dispute that an arbiter rejected as false against the address
+/// that raised it, so repeated bad-faith flags are visible in their profile.
+fn record_false_flag(deps: DepsMut, flagger: &Addr) -> StdResult<()> {
+    let mut flagger_rep = USERS.may_load(deps.storage, flagger)?.unwrap_or(UserReputation {
+        address: flagger.clone(),
+        reputation_score: Uint128::zero(),
+        total_reviews: 0,
+        disputed_reviews: 0,
+        last_activity: Timestamp::from_seconds(0),
+        transaction_volume: Uint128::zero(),
+        reviews_seen: 0,
+        reviews_honored: 0,
+        false_flags_made: 0,
+    });
+    flagger_rep.false_flags_made += 1;
+    USERS.save(deps.storage, flagger, &flagger_rep)
+}
+
+/// Records a newly submitted review as seen and (provisionally) honored.
+/// Called once per `submit_review`, independent of `update_user_reputation`,
+/// so the count survives `sweep_reputation`'s decay instead of being
+/// recomputed from scratch on the reviewer's next interaction.
+fn bump_review_counters(deps: DepsMut, user: &Addr) -> StdResult<()> {
+    let mut user_rep = USERS.load(deps.storage, user)?;
+    user_rep.reviews_seen += 1;
+    user_rep.reviews_honored += 1;
+    USERS.save(deps.storage, user, &user_rep)
+}
+
+/// Moves a review out of the honored count once it's flagged as disputed.
+/// Paired with `restore_review_honor` if the flag is later rejected.
+fn demote_review_honor(deps: DepsMut, user: &Addr) -> StdResult<()> {
+    let mut user_rep = USERS.load(deps.storage, user)?;
+    user_rep.reviews_honored = user_rep.reviews_honored.saturating_sub(1);
+    USERS.save(deps.storage, user, &user_rep)
+}
+
+/// Restores a review to the honored count after an arbiter rejects the
+/// dispute that had demoted it.
+fn restore_review_honor(deps: DepsMut, user: &Addr) -> StdResult<()> {
+    let mut user_rep = USERS.load(deps.storage, user)?;
+    user_rep.reviews_honored = (user_rep.reviews_honored + 1).min(user_rep.reviews_seen);
+    USERS.save(deps.storage, user, &user_rep)
+}
+
+/// Slashes `slash_fraction` percent of a reviewer's bonded stake to the treasury.
+/// Returns `None` if the reviewer has no stake (or nothing to slash).
+fn slash_stake(
+    deps: DepsMut,
+    reviewer: &Addr,
+    params: &ReputationParams,
+) -> StdResult<Option<BankMsg>> {
+    let mut stake = match STAKES.may_load(deps.storage, reviewer)? {
+        Some(stake) if !stake.amount.is_zero() => stake,
+        _ => return Ok(None),
+    };
+
+    let slashed = stake
+        .amount
+        .multiply_ratio(Uint128::from(params.slash_fraction), Uint128::from(100u32));
+    if slashed.is_zero() {
+        return Ok(None);
+    }
+
+    stake.amount -= slashed;
+    STAKES.save(deps.storage, reviewer, &stake)?;
+
+    Ok(Some(BankMsg::Send {
+        to_address: params.treasury.to_string(),
+        amount: vec![Coin {
+            denom: STAKE_DENOM.to_string(),
+            amount: slashed,
+        }],
+    }))
+}
+
+/// Bonds native funds as a reviewer's stake. Multiple deposits accumulate.
+pub fn deposit_stake(deps: DepsMut, _env: Env, info: MessageInfo) -> StdResult<Response> {
+    let amount = info
+        .funds
+        .iter()
+        .find(|coin| coin.denom == STAKE_DENOM)
+        .map(|coin| coin.amount)
+        .unwrap_or_default();
+
+    if amount.is_zero() {
+        return Err(StdError::generic_err(format!(
+            "Must send {} to deposit stake",
+            STAKE_DENOM
+        )));
+    }
+
+    let mut stake = STAKES.may_load(deps.storage, &info.sender)?.unwrap_or(Stake {
+        amount: Uint128::zero(),
+        unlock_at: Timestamp::from_seconds(0),
+    });
+    stake.amount += amount;
+    STAKES.save(deps.storage, &info.sender, &stake)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "deposit_stake")
+        .add_attribute("staker", info.sender)
+        .add_attribute("amount", amount))
+}
+
+/// Withdraws bonded stake, subject to an unbonding delay. The first call
+/// starts the unbonding clock; once `unlock_at` has passed, a second call
+/// sends the bonded funds back to the reviewer.
+pub fn withdraw_stake(deps: DepsMut, env: Env, info: MessageInfo) -> StdResult<Response> {
+    let mut stake = STAKES
+        .may_load(deps.storage, &info.sender)?
+        .filter(|s| !s.amount.is_zero())
+        .ok_or_else(|| StdError::generic_err("No bonded stake to withdraw"))?;
+
+    let params = REPUTATION_PARAMS.load(deps.storage)?;
+
+    if stake.unlock_at.seconds() == 0 {
+        stake.unlock_at = env.block.time.plus_seconds(params.unstake_delay);
+        STAKES.save(deps.storage, &info.sender, &stake)?;
+
+        return Ok(Response::new()
+            .add_attribute("action", "request_unstake")
+            .add_attribute("staker", info.sender)
+            .add_attribute("unlock_at", stake.unlock_at.to_string()));
+    }
+
+    if env.block.time < stake.unlock_at {
+        return Err(StdError::generic_err(format!(
+            "Stake is still locked until {}",
+            stake.unlock_at
+        )));
+    }
+
+    let amount = stake.amount;
+    STAKES.save(
+        deps.storage,
+        &info.sender,
+        &Stake {
+            amount: Uint128::zero(),
+            unlock_at: Timestamp::from_seconds(0),
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_attribute("action", "withdraw_stake")
+        .add_attribute("staker", info.sender.clone())
+        .add_attribute("amount", amount)
+        .add_message(BankMsg::Send {
+            to_address: info.sender.to_string(),
+            amount: vec![Coin {
+                denom: STAKE_DENOM.to_string(),
+                amount,
+            }],
+        }))
+}
+
 fn update_user_reputation(deps: DepsMut, user: &Addr, env: &Env) -> StdResult<()> {
     let mut user_rep = USERS.may_load(deps.storage, user)?.unwrap_or(UserReputation {
         address: user.clone(),
@@ -86,10 +460,13 @@ fn update_user_reputation(deps: DepsMut, user: &Addr, env: &Env) -> StdResult<()
         disputed_reviews: 0,
         last_activity: env.block.time,
         transaction_volume: Uint128::zero(),
+        reviews_seen: 0,
+        reviews_honored: 0,
+        false_flags_made: 0,
     });
 
     let params = REPUTATION_PARAMS.load(deps.storage)?;
-    
+
     // Count total and disputed reviews
     let reviews: Vec<Review> = USER_REVIEWS
         .prefix(user)
@@ -102,6 +479,9 @@ fn update_user_reputation(deps: DepsMut, user: &Addr, env: &Env) -> StdResult<()
 
     user_rep.total_reviews = reviews.len() as u32;
     user_rep.disputed_reviews = reviews.iter().filter(|r| r.is_disputed).count() as u32;
+    // reviews_seen/reviews_honored are NOT recomputed here: they are maintained
+    // incrementally (see bump_review_counters/demote_review_honor/restore_review_honor)
+    // so that sweep_reputation's decay isn't wiped out by the next interaction.
     user_rep.last_activity = env.block.time;
 
     // Calculate new reputation score
@@ -110,4 +490,59 @@ fn update_user_reputation(deps: DepsMut, user: &Addr, env: &Env) -> StdResult<()
     USERS.save(deps.storage, user, &user_rep)?;
 
     Ok(())
+}
+
+/// Periodic maintenance entrypoint that ages out stale `reviews_seen`/`reviews_honored`
+/// counters via exponential decay, one `inactivity_decay_period` at a time, instead of
+/// letting them jump all at once on next read. Paginated like `query_reviews` so a single
+/// call can't be used to exhaust gas sweeping the whole user set.
+pub fn sweep_reputation(
+    deps: DepsMut,
+    env: Env,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<Response> {
+    let limit = limit.unwrap_or(DEFAULT_SWEEP_LIMIT).min(MAX_SWEEP_LIMIT) as usize;
+    let params = REPUTATION_PARAMS.load(deps.storage)?;
+
+    let start = start_after
+        .map(|addr| deps.api.addr_validate(&addr))
+        .transpose()?;
+    let start_bound = start.as_ref().map(Bound::exclusive);
+
+    let candidates: Vec<(Addr, UserReputation)> = USERS
+        .range(deps.storage, start_bound, None, Order::Ascending)
+        .take(limit)
+        .collect::<StdResult<Vec<_>>>()?;
+
+    let mut swept_count = 0u32;
+    for (addr, mut user_rep) in candidates {
+        let elapsed = env.block.time.seconds().saturating_sub(user_rep.last_activity.seconds());
+        if elapsed <= params.inactivity_decay_period {
+            continue;
+        }
+
+        let elapsed_periods = elapsed / params.inactivity_decay_period;
+        for _ in 0..elapsed_periods {
+            // Once both counters are below the denominator, integer division
+            // floors every further reduction to zero: no amount of remaining
+            // elapsed_periods can shrink them any more, so stop instead of
+            // spinning through the rest of a long-inactive account's periods.
+            if user_rep.reviews_seen < SWEEP_DECAY_DENOMINATOR
+                && user_rep.reviews_honored < SWEEP_DECAY_DENOMINATOR
+            {
+                break;
+            }
+            user_rep.reviews_seen -= user_rep.reviews_seen / SWEEP_DECAY_DENOMINATOR;
+            user_rep.reviews_honored -= user_rep.reviews_honored / SWEEP_DECAY_DENOMINATOR;
+        }
+
+        user_rep.reputation_score = calculate_reputation_score(&user_rep, &params, env.block.time)?;
+        USERS.save(deps.storage, &addr, &user_rep)?;
+        swept_count += 1;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "sweep_reputation")
+        .add_attribute("swept_count", swept_count.to_string()))
 }
\ No newline at end of file
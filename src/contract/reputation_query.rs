@@ -3,7 +3,10 @@ use cw_storage_plus::Bound;
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::reputation::{Review, UserReputation, REVIEWS, USER_REVIEWS, USERS};
+use crate::reputation::{
+    reputation_status, DisputeStatus, Review, ReputationParams, ReputationStatus, Stake,
+    UserReputation, DISPUTES, REPUTATION_PARAMS, REVIEWS, STAKES, USER_REVIEWS, USERS,
+};
 
 const DEFAULT_LIMIT: u32 = 10;
 const MAX_LIMIT: u32 = 30;
@@ -28,6 +31,17 @@ pub struct UserReputationResponse {
     pub disputed_reviews: u32,
     pub last_activity: String,
     pub transaction_volume: Uint128,
+    pub status: ReputationStatus,
+    pub bonded_amount: Uint128,
+    pub stake_unlock_at: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct DisputeResponse {
+    pub review_id: String,
+    pub flagger: String,
+    pub reason: String,
+    pub status: DisputeStatus,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -91,6 +105,7 @@ pub fn query_user_reputation(
 ) -> StdResult<UserReputationResponse> {
     let user_addr = deps.api.addr_validate(&user)?;
     let user_rep = USERS.load(deps.storage, &user_addr)?;
+    let params: ReputationParams = REPUTATION_PARAMS.load(deps.storage)?;
 
     // Filter reviews by time range if specified
     let reviews: Vec<Review> = USER_REVIEWS
@@ -121,6 +136,11 @@ pub fn query_user_reputation(
         })
         .collect::<StdResult<Vec<Review>>>()?;
 
+    let stake = STAKES.may_load(deps.storage, &user_addr)?.unwrap_or(Stake {
+        amount: Uint128::zero(),
+        unlock_at: Timestamp::from_seconds(0),
+    });
+
     Ok(UserReputationResponse {
         address: user_rep.address.to_string(),
         reputation_score: user_rep.reputation_score,
@@ -128,6 +148,9 @@ pub fn query_user_reputation(
         disputed_reviews: reviews.iter().filter(|r| r.is_disputed).count() as u32,
         last_activity: user_rep.last_activity.to_string(),
         transaction_volume: user_rep.transaction_volume,
+        status: reputation_status(&user_rep, &params),
+        bonded_amount: stake.amount,
+        stake_unlock_at: stake.unlock_at.to_string(),
     })
 }
 
@@ -166,4 +189,37 @@ pub fn query_reputation_stats(deps: Deps) -> StdResult<ReputationStatsResponse>
         average_rating,
         disputed_reviews,
     })
+}
+
+pub fn query_disputes(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+    status: Option<DisputeStatus>,
+) -> StdResult<Vec<DisputeResponse>> {
+    let limit = limit.unwrap_or(DEFAULT_LIMIT).min(MAX_LIMIT) as usize;
+    let start = start_after.map(Bound::exclusive);
+
+    let disputes: Vec<DisputeResponse> = DISPUTES
+        .range(deps.storage, start, None, Order::Ascending)
+        .filter(|d| {
+            if let Ok((_, dispute)) = d {
+                status.as_ref().map_or(true, |s| &dispute.status == s)
+            } else {
+                false
+            }
+        })
+        .take(limit)
+        .map(|item| {
+            let (_, dispute) = item?;
+            Ok(DisputeResponse {
+                review_id: dispute.review_id,
+                flagger: dispute.flagger.to_string(),
+                reason: dispute.reason,
+                status: dispute.status,
+            })
+        })
+        .collect::<StdResult<Vec<DisputeResponse>>>()?;
+
+    Ok(disputes)
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use cosmwasm_std::{Addr, Timestamp, Uint128, StdResult, StdError};
+use cosmwasm_std::{Addr, Api, Timestamp, Uint128, StdResult, StdError};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use cw_storage_plus::{Map, Item};
@@ -25,6 +25,9 @@ pub struct UserReputation {
     pub disputed_reviews: u32,
     pub last_activity: Timestamp,
     pub transaction_volume: Uint128,
+    pub reviews_seen: u32,
+    pub reviews_honored: u32,
+    pub false_flags_made: u32,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
@@ -34,12 +37,87 @@ pub struct ReputationParams {
     pub dispute_penalty: u32,
     pub inactivity_decay_period: u64,
     pub decay_rate: u32,
+    pub throttling_slack: u32,
+    pub ban_slack: u32,
+    pub throttle_window: u64,
+    pub min_stake: Uint128,
+    pub unstake_delay: u64,
+    pub slash_fraction: u32,
+    pub treasury: Addr,
+}
+
+/// Bonded stake held by a reviewer. `unlock_at` is zero while the stake is
+/// actively bonded; it is set once an unbonding request starts the unstake
+/// delay clock.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Stake {
+    pub amount: Uint128,
+    pub unlock_at: Timestamp,
+}
+
+/// Native denom accepted for reviewer stake deposits and slashing payouts.
+pub const STAKE_DENOM: &str = "uxlm";
+
+/// Lifecycle of a flagged review awaiting arbitration.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum DisputeStatus {
+    Open,
+    Upheld,
+    Rejected,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Dispute {
+    pub review_id: String,
+    pub flagger: Addr,
+    pub reason: String,
+    pub status: DisputeStatus,
+}
+
+pub const DISPUTES: Map<String, Dispute> = Map::new("disputes");
+/// Allow-list of addresses permitted to flag and resolve disputes. Seeded at
+/// `instantiate` and rotated afterward only by `ADMIN` via `set_arbiters`.
+pub const ARBITERS: Item<Vec<Addr>> = Item::new("arbiters");
+/// Address permitted to rotate `ARBITERS` via `set_arbiters`. Set once at
+/// `instantiate`.
+pub const ADMIN: Item<Addr> = Item::new("admin");
+
+/// Denominator used to derive the minimum number of honored reviews expected
+/// for a given number of reviews seen (e.g. 10 => at least 1 in 10 expected honored).
+pub const MIN_HONOR_RATE_DENOMINATOR: u32 = 10;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub enum ReputationStatus {
+    Ok,
+    Throttled,
+    Banned,
 }
 
 pub const REVIEWS: Map<String, Review> = Map::new("reviews");
 pub const USER_REVIEWS: Map<(&Addr, String), String> = Map::new("user_reviews");
 pub const USERS: Map<&Addr, UserReputation> = Map::new("users");
 pub const REPUTATION_PARAMS: Item<ReputationParams> = Item::new("reputation_params");
+pub const STAKES: Map<&Addr, Stake> = Map::new("stakes");
+/// Reviewer address -> the ed25519 public key bound to it. Registered once via
+/// `register_reviewer_key` and reused for every subsequent `submit_review`, so
+/// a reviewer cannot mint a fresh throwaway keypair per submission.
+pub const REVIEWER_KEYS: Map<&Addr, Vec<u8>> = Map::new("reviewer_keys");
+
+/// Derives a reviewer's throttling/banning status from their honor rate with
+/// an inclusion-rate heuristic: a reviewer is
+/// expected to have at least `reviews_seen / MIN_HONOR_RATE_DENOMINATOR`
+/// honored reviews, with slack before throttling and more slack before a ban.
+pub fn reputation_status(user: &UserReputation, params: &ReputationParams) -> ReputationStatus {
+    let min_expected = user.reviews_seen / MIN_HONOR_RATE_DENOMINATOR;
+
+    if min_expected <= user.reviews_honored + params.throttling_slack {
+        ReputationStatus::Ok
+    } else if min_expected <= user.reviews_honored + params.ban_slack {
+        ReputationStatus::Throttled
+    } else {
+        ReputationStatus::Banned
+    }
+}
 
 pub fn calculate_reputation_score(
     user: &UserReputation,
@@ -48,11 +126,20 @@ pub fn calculate_reputation_score(
 ) -> StdResult<Uint128> {
     let base_score = Uint128::from(user.total_reviews.saturating_sub(user.disputed_reviews));
     
-    // Time weight calculation
+    // Time weight calculation. Applying the decay rate via `pow` would overflow
+    // for large decay_periods, so instead apply it one period at a time with
+    // multiply_ratio, stopping early once the weight has decayed to zero.
     let time_since_last_activity = current_time.seconds() - user.last_activity.seconds();
     let time_weight = if time_since_last_activity > params.inactivity_decay_period {
         let decay_periods = time_since_last_activity / params.inactivity_decay_period;
-        Uint128::from(params.decay_rate).pow(decay_periods as u32)
+        let mut weight = Uint128::from(params.time_weight_factor);
+        for _ in 0..decay_periods {
+            if weight.is_zero() {
+                break;
+            }
+            weight = weight.multiply_ratio(Uint128::from(params.decay_rate), Uint128::from(100u32));
+        }
+        weight
     } else {
         Uint128::from(params.time_weight_factor)
     };
@@ -76,12 +163,68 @@ pub fn calculate_reputation_score(
     Ok(weighted_score.saturating_sub(dispute_penalty))
 }
 
-pub fn verify_transaction_proof(proof: &str) -> StdResult<bool> {
-    // TODO: Implement Stellar transaction verification logic
-    Ok(true)
+/// A Stellar transaction proof submitted alongside a review. The expected
+/// wire format is `<source_account>|<memo>`, mirroring how a Stellar
+/// transaction binds a source account and a memo/operation payload; the
+/// memo is expected to embed the id of the reviewed service.
+pub struct StellarTransactionProof {
+    pub source_account: String,
+    pub memo: String,
+}
+
+fn parse_transaction_proof(proof: &str) -> StdResult<StellarTransactionProof> {
+    let mut parts = proof.splitn(2, '|');
+    let source_account = parts
+        .next()
+        .filter(|s| !s.is_empty())
+        .ok_or_else(|| StdError::generic_err("Malformed transaction proof: missing source account"))?
+        .to_string();
+    let memo = parts
+        .next()
+        .ok_or_else(|| StdError::generic_err("Malformed transaction proof: missing memo"))?
+        .to_string();
+
+    Ok(StellarTransactionProof { source_account, memo })
 }
 
-pub fn verify_signature(message: &[u8], signature: &[u8], public_key: &[u8]) -> StdResult<bool> {
-    // TODO: Implement signature verification logic
+/// Verifies that a transaction proof is well-formed and binds the reviewer
+/// (the proof's source account must equal `reviewer`) to the reviewed
+/// `service` (the memo's leading `<service>-...` segment must equal it
+/// exactly, not merely contain it as a substring — otherwise a proof for
+/// "service10" would also pass verification for "service1").
+pub fn verify_transaction_proof(proof: &str, reviewer: &Addr, service: &str) -> StdResult<bool> {
+    let parsed = parse_transaction_proof(proof)?;
+
+    if parsed.source_account != reviewer.as_str() {
+        return Ok(false);
+    }
+
+    if parsed.memo.splitn(2, '-').next() != Some(service) {
+        return Ok(false);
+    }
+
     Ok(true)
+}
+
+/// Builds the canonical message a reviewer signs over: the reviewer address,
+/// the reviewed service, the rating, and the transaction proof, joined so
+/// that no field can be shifted into another.
+pub fn review_signing_message(
+    reviewer: &Addr,
+    service: &str,
+    rating: u8,
+    transaction_proof: &str,
+) -> Vec<u8> {
+    format!("{}:{}:{}:{}", reviewer, service, rating, transaction_proof).into_bytes()
+}
+
+/// Verifies an ed25519 signature over `message` using the chain's crypto API,
+/// delegating to the host implementation rather than a bundled crypto crate.
+pub fn verify_signature(
+    api: &dyn Api,
+    message: &[u8],
+    signature: &[u8],
+    public_key: &[u8],
+) -> StdResult<bool> {
+    api.ed25519_verify(message, signature, public_key)
 }
\ No newline at end of file
@@ -1,142 +1,649 @@
-use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
-use cosmwasm_std::{Addr, Uint128, Timestamp};
-
-use crate::contract::marketplace_reputation::{submit_review, flag_dispute};
-use crate::reputation::{ReputationParams, REPUTATION_PARAMS, REVIEWS, USERS};
-
-#[test]
-fn test_submit_review() {
-    let mut deps = mock_dependencies();
-    let env = mock_env();
-    let info = mock_info("user1", &[]);
-
-    // Set up reputation parameters
-    let params = ReputationParams {
-        time_weight_factor: 10,
-        volume_weight_factor: 5,
-        dispute_penalty: 20,
-        inactivity_decay_period: 2592000, // 30 days in seconds
-        decay_rate: 95, // 95% retention rate
-    };
-    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
-
-    // Submit a review
-    let res = submit_review(
-        deps.as_mut(),
-        env.clone(),
-        info.clone(),
-        "service1".to_string(),
-        5,
-        "Great service!".to_string(),
-        "tx_proof_123".to_string(),
-        vec![1, 2, 3], // Mock signature
-    ).unwrap();
-
-    // Check response attributes
-    assert_eq!(res.attributes.len(), 4);
-    assert_eq!(res.attributes[0].key, "action");
-    assert_eq!(res.attributes[0].value, "submit_review");
-
-    // Verify review was stored
-    let review_id = format!("{}-{}", env.block.time.seconds(), info.sender);
-    let stored_review = REVIEWS.load(deps.as_ref().storage, review_id).unwrap();
-    assert_eq!(stored_review.rating, 5);
-    assert_eq!(stored_review.service, "service1");
-    assert_eq!(stored_review.is_disputed, false);
-
-    // Check user reputation was created
-    let user_rep = USERS.load(deps.as_ref().storage, &Addr::unchecked("user1")).unwrap();
-    assert_eq!(user_rep.total_reviews, 1);
-    assert_eq!(user_rep.disputed_reviews, 0);
-}
-
-#[test]
-fn test_flag_dispute() {
-    let mut deps = mock_dependencies();
-    let env = mock_env();
-    let reviewer = mock_info("user1", &[]);
-    let admin = mock_info("admin", &[]);
-
-    // Set up reputation parameters
-    let params = ReputationParams {
-        time_weight_factor: 10,
-        volume_weight_factor: 5,
-        dispute_penalty: 20,
-        inactivity_decay_period: 2592000,
-        decay_rate: 95,
-    };
-    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
-
-    // Submit a review first
-    let review_id = format!("{}-{}", env.block.time.seconds(), reviewer.sender);
-    submit_review(
-        deps.as_mut(),
-        env.clone(),
-        reviewer.clone(),
-        "service1".to_string(),
-        5,
-        "Great service!".to_string(),
-        "tx_proof_123".to_string(),
-        vec![1, 2, 3],
-    ).unwrap();
-
-    // Flag the review as disputed
-    let res = flag_dispute(
-        deps.as_mut(),
-        env.clone(),
-        admin,
-        review_id.clone(),
-        "Fake review".to_string(),
-    ).unwrap();
-
-    // Check response attributes
-    assert_eq!(res.attributes.len(), 4);
-    assert_eq!(res.attributes[0].key, "action");
-    assert_eq!(res.attributes[0].value, "flag_dispute");
-
-    // Verify review was updated
-    let disputed_review = REVIEWS.load(deps.as_ref().storage, review_id).unwrap();
-    assert_eq!(disputed_review.is_disputed, true);
-    assert_eq!(disputed_review.dispute_reason, Some("Fake review".to_string()));
-
-    // Check user reputation was updated
-    let user_rep = USERS.load(deps.as_ref().storage, &reviewer.sender).unwrap();
-    assert_eq!(user_rep.disputed_reviews, 1);
-}
-
-#[test]
-fn test_reputation_calculation() {
-    let mut deps = mock_dependencies();
-    let mut env = mock_env();
-    let info = mock_info("user1", &[]);
-
-    // Set up reputation parameters
-    let params = ReputationParams {
-        time_weight_factor: 10,
-        volume_weight_factor: 5,
-        dispute_penalty: 20,
-        inactivity_decay_period: 2592000,
-        decay_rate: 95,
-    };
-    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
-
-    // Submit multiple reviews
-    for i in 0..3 {
-        env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 86400); // Add 1 day
-        submit_review(
-            deps.as_mut(),
-            env.clone(),
-            info.clone(),
-            format!("service{}", i),
-            5,
-            "Great service!".to_string(),
-            format!("tx_proof_{}", i),
-            vec![1, 2, 3],
-        ).unwrap();
-    }
-
-    // Check final reputation score
-    let user_rep = USERS.load(deps.as_ref().storage, &info.sender).unwrap();
-    assert_eq!(user_rep.total_reviews, 3);
-    assert!(user_rep.reputation_score > Uint128::zero());
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{coins, Addr, Uint128, Timestamp};
+
+use crate::contract::marketplace_reputation::{
+    deposit_stake, flag_dispute, instantiate, register_reviewer_key, resolve_dispute, set_arbiters,
+    submit_review, sweep_reputation, withdraw_stake,
+};
+use crate::reputation::{
+    DisputeStatus, ReputationParams, ADMIN, ARBITERS, DISPUTES, REPUTATION_PARAMS, REVIEWS, STAKES,
+    USERS,
+};
+
+// Ed25519 keypair used to sign reviews in these tests. Signatures below are
+// over `review_signing_message("user1", service, rating, transaction_proof)`.
+const REVIEWER_PUBLIC_KEY: [u8; 32] = [
+    40, 232, 64, 170, 143, 34, 17, 51, 201, 58, 21, 144, 217, 10, 55, 234, 119, 90, 135, 15, 131,
+    16, 184, 195, 189, 15, 152, 67, 8, 236, 250, 64,
+];
+
+// Signs "user1:service1:5:user1|service1-tx_proof_123"
+const SIG_SERVICE1_TX123: [u8; 64] = [
+    115, 20, 141, 68, 147, 255, 167, 116, 15, 84, 162, 99, 76, 155, 55, 197, 83, 246, 72, 132,
+    131, 207, 79, 196, 53, 88, 80, 39, 33, 140, 60, 115, 143, 97, 78, 157, 5, 151, 212, 189, 144,
+    34, 32, 17, 180, 173, 117, 217, 247, 215, 65, 165, 193, 134, 27, 110, 219, 251, 210, 133, 208,
+    57, 105, 0,
+];
+
+// Signs "user1:service0:5:user1|service0-tx_proof_0"
+const SIG_SERVICE0_TX0: [u8; 64] = [
+    87, 93, 145, 48, 151, 215, 39, 232, 243, 18, 103, 3, 245, 177, 250, 192, 206, 246, 213, 192,
+    157, 124, 54, 76, 166, 10, 203, 17, 254, 80, 149, 201, 229, 93, 10, 245, 240, 143, 64, 126,
+    145, 137, 208, 24, 206, 5, 12, 31, 62, 197, 41, 219, 96, 3, 243, 184, 138, 190, 10, 190, 65,
+    115, 226, 14,
+];
+
+// Signs "user1:service1:5:user1|service1-tx_proof_1"
+const SIG_SERVICE1_TX1: [u8; 64] = [
+    53, 180, 232, 37, 89, 234, 22, 82, 90, 243, 94, 49, 195, 234, 152, 185, 29, 55, 95, 57, 210,
+    253, 161, 239, 14, 52, 40, 112, 50, 55, 175, 111, 244, 4, 109, 0, 20, 31, 173, 126, 184, 90,
+    203, 87, 195, 115, 185, 113, 77, 93, 75, 2, 86, 41, 145, 242, 225, 228, 181, 36, 42, 44, 77, 7,
+];
+
+// Signs "user1:service2:5:user1|service2-tx_proof_2"
+const SIG_SERVICE2_TX2: [u8; 64] = [
+    93, 90, 48, 170, 78, 246, 216, 185, 79, 165, 87, 245, 120, 13, 24, 195, 86, 106, 203, 100, 6,
+    232, 42, 248, 65, 116, 247, 245, 146, 106, 15, 159, 171, 152, 138, 114, 163, 122, 141, 202,
+    142, 125, 133, 39, 31, 111, 145, 54, 35, 36, 177, 57, 100, 61, 79, 166, 82, 82, 81, 186, 141,
+    227, 236, 9,
+];
+
+#[test]
+fn test_submit_review() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("user1", &[]);
+
+    // Set up reputation parameters
+    let params = ReputationParams {
+        time_weight_factor: 10,
+        volume_weight_factor: 5,
+        dispute_penalty: 20,
+        inactivity_decay_period: 2592000, // 30 days in seconds
+        decay_rate: 95, // 95% retention rate
+        throttling_slack: 10,
+        ban_slack: 50,
+        throttle_window: 86400,
+        min_stake: Uint128::zero(),
+        unstake_delay: 604800,
+        slash_fraction: 50,
+        treasury: Addr::unchecked("treasury"),
+    };
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    // Submit a review
+    let res = submit_review(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap();
+
+    // Check response attributes
+    assert_eq!(res.attributes.len(), 4);
+    assert_eq!(res.attributes[0].key, "action");
+    assert_eq!(res.attributes[0].value, "submit_review");
+
+    // Verify review was stored
+    let review_id = format!("{}-{}", env.block.time.seconds(), info.sender);
+    let stored_review = REVIEWS.load(deps.as_ref().storage, review_id).unwrap();
+    assert_eq!(stored_review.rating, 5);
+    assert_eq!(stored_review.service, "service1");
+    assert_eq!(stored_review.is_disputed, false);
+
+    // Check user reputation was created
+    let user_rep = USERS.load(deps.as_ref().storage, &Addr::unchecked("user1")).unwrap();
+    assert_eq!(user_rep.total_reviews, 1);
+    assert_eq!(user_rep.disputed_reviews, 0);
+}
+
+#[test]
+fn test_flag_dispute() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let reviewer = mock_info("user1", &[]);
+    let admin = mock_info("admin", &[]);
+
+    // Set up reputation parameters
+    let params = ReputationParams {
+        time_weight_factor: 10,
+        volume_weight_factor: 5,
+        dispute_penalty: 20,
+        inactivity_decay_period: 2592000,
+        decay_rate: 95,
+        throttling_slack: 10,
+        ban_slack: 50,
+        throttle_window: 86400,
+        min_stake: Uint128::zero(),
+        unstake_delay: 604800,
+        slash_fraction: 50,
+        treasury: Addr::unchecked("treasury"),
+    };
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+    ARBITERS
+        .save(deps.as_mut().storage, &vec![admin.sender.clone()])
+        .unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        reviewer.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    // Submit a review first
+    let review_id = format!("{}-{}", env.block.time.seconds(), reviewer.sender);
+    submit_review(
+        deps.as_mut(),
+        env.clone(),
+        reviewer.clone(),
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap();
+
+    // Flag the review as disputed
+    let res = flag_dispute(
+        deps.as_mut(),
+        env.clone(),
+        admin,
+        review_id.clone(),
+        "Fake review".to_string(),
+    ).unwrap();
+
+    // Check response attributes
+    assert_eq!(res.attributes.len(), 4);
+    assert_eq!(res.attributes[0].key, "action");
+    assert_eq!(res.attributes[0].value, "flag_dispute");
+
+    // Verify review was updated
+    let disputed_review = REVIEWS.load(deps.as_ref().storage, review_id).unwrap();
+    assert_eq!(disputed_review.is_disputed, true);
+    assert_eq!(disputed_review.dispute_reason, Some("Fake review".to_string()));
+
+    // Check user reputation was updated
+    let user_rep = USERS.load(deps.as_ref().storage, &reviewer.sender).unwrap();
+    assert_eq!(user_rep.disputed_reviews, 1);
+}
+
+#[test]
+fn test_reputation_calculation() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let info = mock_info("user1", &[]);
+
+    // Set up reputation parameters
+    let params = ReputationParams {
+        time_weight_factor: 10,
+        volume_weight_factor: 5,
+        dispute_penalty: 20,
+        inactivity_decay_period: 2592000,
+        decay_rate: 95,
+        throttling_slack: 10,
+        ban_slack: 50,
+        throttle_window: 86400,
+        min_stake: Uint128::zero(),
+        unstake_delay: 604800,
+        slash_fraction: 50,
+        treasury: Addr::unchecked("treasury"),
+    };
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    // Submit multiple reviews
+    let signatures = [
+        SIG_SERVICE0_TX0.to_vec(),
+        SIG_SERVICE1_TX1.to_vec(),
+        SIG_SERVICE2_TX2.to_vec(),
+    ];
+    for i in 0..3 {
+        env.block.time = Timestamp::from_seconds(env.block.time.seconds() + 86400); // Add 1 day
+        submit_review(
+            deps.as_mut(),
+            env.clone(),
+            info.clone(),
+            format!("service{}", i),
+            5,
+            "Great service!".to_string(),
+            format!("user1|service{}-tx_proof_{}", i, i),
+            signatures[i].clone(),
+        ).unwrap();
+    }
+
+    // Check final reputation score
+    let user_rep = USERS.load(deps.as_ref().storage, &info.sender).unwrap();
+    assert_eq!(user_rep.total_reviews, 3);
+    assert!(user_rep.reputation_score > Uint128::zero());
+}
+
+#[test]
+fn test_sweep_decay_persists_through_next_interaction() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let info = mock_info("user1", &[]);
+
+    let params = ReputationParams {
+        time_weight_factor: 10,
+        volume_weight_factor: 5,
+        dispute_penalty: 20,
+        inactivity_decay_period: 2592000,
+        decay_rate: 95,
+        throttling_slack: 10,
+        ban_slack: 50,
+        throttle_window: 86400,
+        min_stake: Uint128::zero(),
+        unstake_delay: 604800,
+        slash_fraction: 50,
+        treasury: Addr::unchecked("treasury"),
+    };
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    submit_review(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap();
+
+    // Inflate the counters to values a sweep should visibly decay.
+    let mut user_rep = USERS.load(deps.as_ref().storage, &info.sender).unwrap();
+    user_rep.reviews_seen = 240;
+    user_rep.reviews_honored = 240;
+    USERS.save(deps.as_mut().storage, &info.sender, &user_rep).unwrap();
+
+    // Advance just past one decay period and sweep.
+    env.block.time = env.block.time.plus_seconds(params.inactivity_decay_period + 1);
+    sweep_reputation(deps.as_mut(), env.clone(), None, None).unwrap();
+
+    let swept_rep = USERS.load(deps.as_ref().storage, &info.sender).unwrap();
+    assert!(swept_rep.reviews_seen < 240);
+    assert!(swept_rep.reviews_honored < 240);
+    let decayed_seen = swept_rep.reviews_seen;
+    let decayed_honored = swept_rep.reviews_honored;
+
+    // A subsequent review submission must build on the decayed values, not
+    // recompute them from scratch and wipe the sweep's effect.
+    submit_review(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        "service2".to_string(),
+        5,
+        "Another great service!".to_string(),
+        "user1|service2-tx_proof_2".to_string(),
+        SIG_SERVICE2_TX2.to_vec(),
+    ).unwrap();
+
+    let final_rep = USERS.load(deps.as_ref().storage, &info.sender).unwrap();
+    assert_eq!(final_rep.reviews_seen, decayed_seen + 1);
+    assert_eq!(final_rep.reviews_honored, decayed_honored + 1);
+}
+
+fn default_params() -> ReputationParams {
+    ReputationParams {
+        time_weight_factor: 10,
+        volume_weight_factor: 5,
+        dispute_penalty: 20,
+        inactivity_decay_period: 2592000,
+        decay_rate: 95,
+        throttling_slack: 10,
+        ban_slack: 50,
+        throttle_window: 86400,
+        min_stake: Uint128::zero(),
+        unstake_delay: 604800,
+        slash_fraction: 50,
+        treasury: Addr::unchecked("treasury"),
+    }
+}
+
+#[test]
+fn test_slash_only_on_upheld_dispute() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let reviewer = mock_info("user1", &[]);
+    let admin = mock_info("admin", &[]);
+    let params = default_params();
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+    ARBITERS
+        .save(deps.as_mut().storage, &vec![admin.sender.clone()])
+        .unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        reviewer.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    deposit_stake(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("user1", &coins(1000, "uxlm")),
+    ).unwrap();
+
+    let review_id = format!("{}-{}", env.block.time.seconds(), reviewer.sender);
+    submit_review(
+        deps.as_mut(),
+        env.clone(),
+        reviewer.clone(),
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap();
+
+    // Flagging alone must not slash anything.
+    let flag_res = flag_dispute(
+        deps.as_mut(),
+        env.clone(),
+        admin.clone(),
+        review_id.clone(),
+        "Fake review".to_string(),
+    ).unwrap();
+    assert!(flag_res.messages.is_empty());
+    let stake_after_flag = STAKES.load(deps.as_ref().storage, &reviewer.sender).unwrap();
+    assert_eq!(stake_after_flag.amount, Uint128::from(1000u128));
+
+    // Upholding the dispute is what triggers the slash.
+    let resolve_res = resolve_dispute(
+        deps.as_mut(),
+        env.clone(),
+        admin,
+        review_id,
+        DisputeStatus::Upheld,
+    ).unwrap();
+    assert_eq!(resolve_res.messages.len(), 1);
+    let stake_after_resolve = STAKES.load(deps.as_ref().storage, &reviewer.sender).unwrap();
+    assert_eq!(stake_after_resolve.amount, Uint128::from(500u128));
+}
+
+#[test]
+fn test_resolve_dispute_rejected_clears_flag_and_records_false_flag() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let reviewer = mock_info("user1", &[]);
+    let admin = mock_info("admin", &[]);
+    let params = default_params();
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+    ARBITERS
+        .save(deps.as_mut().storage, &vec![admin.sender.clone()])
+        .unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        reviewer.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    let review_id = format!("{}-{}", env.block.time.seconds(), reviewer.sender);
+    submit_review(
+        deps.as_mut(),
+        env.clone(),
+        reviewer.clone(),
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap();
+
+    flag_dispute(
+        deps.as_mut(),
+        env.clone(),
+        admin.clone(),
+        review_id.clone(),
+        "Fake review".to_string(),
+    ).unwrap();
+
+    resolve_dispute(
+        deps.as_mut(),
+        env.clone(),
+        admin,
+        review_id.clone(),
+        DisputeStatus::Rejected,
+    ).unwrap();
+
+    let review = REVIEWS.load(deps.as_ref().storage, review_id.clone()).unwrap();
+    assert_eq!(review.is_disputed, false);
+
+    let dispute = DISPUTES.load(deps.as_ref().storage, review_id).unwrap();
+    assert_eq!(dispute.status, DisputeStatus::Rejected);
+
+    let flagger_rep = USERS.load(deps.as_ref().storage, &admin.sender).unwrap();
+    assert_eq!(flagger_rep.false_flags_made, 1);
+}
+
+#[test]
+fn test_withdraw_stake_enforces_unbonding_delay() {
+    let mut deps = mock_dependencies();
+    let mut env = mock_env();
+    let params = default_params();
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    deposit_stake(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("user1", &coins(1000, "uxlm")),
+    ).unwrap();
+
+    let info = mock_info("user1", &[]);
+
+    // First call only starts the unbonding clock; no funds move yet.
+    let request_res = withdraw_stake(deps.as_mut(), env.clone(), info.clone()).unwrap();
+    assert!(request_res.messages.is_empty());
+    assert_eq!(request_res.attributes[0].value, "request_unstake");
+
+    // Calling again before the delay elapses must fail.
+    let err = withdraw_stake(deps.as_mut(), env.clone(), info.clone()).unwrap_err();
+    assert!(err.to_string().contains("still locked"));
+
+    // Once the unstake delay has passed, the withdrawal succeeds and sends funds.
+    env.block.time = env.block.time.plus_seconds(params.unstake_delay + 1);
+    let final_res = withdraw_stake(deps.as_mut(), env.clone(), info).unwrap();
+    assert_eq!(final_res.messages.len(), 1);
+    assert_eq!(final_res.attributes[0].value, "withdraw_stake");
+
+    let stake = STAKES.load(deps.as_ref().storage, &Addr::unchecked("user1")).unwrap();
+    assert!(stake.amount.is_zero());
+}
+
+#[test]
+fn test_instantiate_seeds_admin_and_arbiters_and_set_arbiters_rotates() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let admin = mock_info("admin", &[]);
+    let arbiter1 = Addr::unchecked("arbiter1");
+
+    instantiate(
+        deps.as_mut(),
+        env.clone(),
+        admin.clone(),
+        None,
+        vec![arbiter1.clone()],
+    ).unwrap();
+
+    assert_eq!(ADMIN.load(deps.as_ref().storage).unwrap(), admin.sender);
+    assert_eq!(
+        ARBITERS.load(deps.as_ref().storage).unwrap(),
+        vec![arbiter1]
+    );
+
+    // A non-admin cannot rotate the arbiter set.
+    let arbiter2 = Addr::unchecked("arbiter2");
+    let err = set_arbiters(
+        deps.as_mut(),
+        env.clone(),
+        mock_info("not-admin", &[]),
+        vec![arbiter2.clone()],
+    ).unwrap_err();
+    assert!(err.to_string().contains("Unauthorized"));
+
+    // The admin can rotate the arbiter set.
+    set_arbiters(deps.as_mut(), env, admin, vec![arbiter2.clone()]).unwrap();
+    assert_eq!(ARBITERS.load(deps.as_ref().storage).unwrap(), vec![arbiter2]);
+}
+
+#[test]
+fn test_submit_review_rejects_banned_reviewer() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("user1", &[]);
+    let params = default_params();
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    // Manufacture a reviewer whose honor rate is far below even the
+    // ban_slack-adjusted minimum, so reputation_status returns Banned.
+    let user_rep = crate::reputation::UserReputation {
+        address: info.sender.clone(),
+        reputation_score: Uint128::zero(),
+        total_reviews: 0,
+        disputed_reviews: 0,
+        last_activity: env.block.time,
+        transaction_volume: Uint128::zero(),
+        reviews_seen: 1000,
+        reviews_honored: 0,
+        false_flags_made: 0,
+    };
+    USERS.save(deps.as_mut().storage, &info.sender, &user_rep).unwrap();
+
+    let err = submit_review(
+        deps.as_mut(),
+        env,
+        info,
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap_err();
+    assert!(err.to_string().contains("banned"));
+}
+
+#[test]
+fn test_submit_review_rejects_insufficient_stake() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("user1", &[]);
+    let mut params = default_params();
+    params.min_stake = Uint128::from(1000u128);
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    // No stake has been deposited, so the reviewer falls short of min_stake.
+    let err = submit_review(
+        deps.as_mut(),
+        env,
+        info,
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap_err();
+    assert!(err.to_string().contains("minimum required stake"));
+}
+
+#[test]
+fn test_submit_review_rejects_proof_for_mismatched_service() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("user1", &[]);
+    let params = default_params();
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    // The proof's memo is bound to "service11", not the "service1" being
+    // reviewed here, so a substring match would wrongly accept it.
+    let err = submit_review(
+        deps.as_mut(),
+        env,
+        info,
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service11-tx_proof_123".to_string(),
+        SIG_SERVICE1_TX123.to_vec(),
+    ).unwrap_err();
+    assert!(err.to_string().contains("Invalid transaction proof"));
+}
+
+#[test]
+fn test_submit_review_rejects_forged_signature() {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+    let info = mock_info("user1", &[]);
+    let params = default_params();
+    REPUTATION_PARAMS.save(deps.as_mut().storage, &params).unwrap();
+
+    register_reviewer_key(
+        deps.as_mut(),
+        env.clone(),
+        info.clone(),
+        REVIEWER_PUBLIC_KEY.to_vec(),
+    ).unwrap();
+
+    // SIG_SERVICE0_TX0 is a valid signature, but over a different message
+    // (service0's fields), so it must not verify against service1's fields.
+    let err = submit_review(
+        deps.as_mut(),
+        env,
+        info,
+        "service1".to_string(),
+        5,
+        "Great service!".to_string(),
+        "user1|service1-tx_proof_123".to_string(),
+        SIG_SERVICE0_TX0.to_vec(),
+    ).unwrap_err();
+    assert!(err.to_string().contains("Invalid review signature"));
 }
\ No newline at end of file